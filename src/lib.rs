@@ -1,6 +1,10 @@
 //! This crate provides the `hex!` macro for converting
 //! hexadecimal string literals to a byte array at compile
-//! time.
+//! time, the `hexfloat!` macro for parsing C99-style hex
+//! floating-point literals into an `f32`/`f64` at compile time,
+//! the `hex_cstr!` macro for building NUL-terminated byte
+//! arrays for FFI, and the `hex_encode!` macro for going the
+//! other way, rendering a byte array back into a hex string.
 //!
 //! # Examples
 //! ```
@@ -13,6 +17,51 @@
 //! assert_eq!(hex!("E5 E6 90 92"), [0xE5, 0xE6, 0x90, 0x92]);
 //! assert_eq!(hex!("0a0B0C0d"), [10, 11, 12, 13]);
 //! assert_eq!(hex!(0a "01" 0C 02), [10, 1, 12, 2]);
+//! assert_eq!(hex!(u32: "DEADBEEF"), 0xDEADBEEF_u32);
+//! assert_eq!(hex!(u32 le: "01000000"), 1_u32);
+//!
+//! use hexlit::hexfloat;
+//!
+//! const PI_ISH: f64 = hexfloat!("0x1.8p3");
+//! assert_eq!(PI_ISH, 12.0_f64);
+//! assert_eq!(hexfloat!(f32: "0x1p0"), 1.0_f32);
+//!
+//! use hexlit::hex_cstr;
+//!
+//! assert_eq!(hex_cstr!("68656C6C6F"), [0x68, 0x65, 0x6C, 0x6C, 0x6F, 0]);
+//!
+//! use hexlit::hex_encode;
+//!
+//! assert_eq!(hex_encode!(&[0xDE, 0xAD, 0xBE, 0xEF]), "deadbeef");
+//! assert_eq!(hex_encode!(upper: &[0xDE, 0xAD, 0xBE, 0xEF]), "DEADBEEF");
+//! ```
+//!
+//! Malformed input is rejected at compile time rather than silently
+//! producing a truncated or wrong value:
+//! ```compile_fail
+//! use hexlit::hex;
+//! // A trailing, unpaired hex digit can't form a full byte.
+//! const BAD: [u8; 1] = hex!(00 0);
+//! ```
+//! ```compile_fail
+//! use hexlit::hexfloat;
+//! // Trailing characters after the exponent are rejected.
+//! const BAD: f64 = hexfloat!("0x1p3junk");
+//! ```
+//! ```compile_fail
+//! use hexlit::hexfloat;
+//! // An exponent needs at least one digit.
+//! const BAD: f64 = hexfloat!("0x1p");
+//! ```
+//! ```compile_fail
+//! use hexlit::hexfloat;
+//! // The mantissa needs at least one digit.
+//! const BAD: f64 = hexfloat!("0xp3");
+//! ```
+//! ```compile_fail
+//! use hexlit::hex_cstr;
+//! // An embedded NUL byte would terminate the C string early.
+//! const BAD: [u8; 3] = hex_cstr!("0001");
 //! ```
 #![no_std]
 
@@ -24,6 +73,14 @@ macro_rules! require_even_number_digits {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! require_fits_in_width {
+    ($len:expr, $width:expr) => {
+        let _: $crate::internals::Fits<[(); ($len <= $width) as usize]>;
+    };
+}
+
 #[macro_export]
 macro_rules! hex {
     (@string $arg:expr) => {{
@@ -32,11 +89,91 @@ macro_rules! hex {
         $crate::require_even_number_digits!(RAW_LENGTH);
         $crate::internals::convert::<{RAW_LENGTH / 2}, {$arg.len()}>(&DATA)
     }};
+    (@int $int:ty, $fold:ident, $endian:ident, $arg:expr) => {{
+        const DATA: &[u8] = $arg.as_bytes();
+        const RAW_LENGTH: usize = $arg.len() - $crate::internals::count_skipped(&DATA);
+        $crate::require_even_number_digits!(RAW_LENGTH);
+        $crate::require_fits_in_width!(RAW_LENGTH / 2, core::mem::size_of::<$int>());
+        const BYTES: [u8; RAW_LENGTH / 2] =
+            $crate::internals::convert::<{RAW_LENGTH / 2}, {$arg.len()}>(&DATA);
+        $crate::internals::$fold(&BYTES, $crate::internals::Endian::$endian)
+    }};
+    (u16 le: $lit:literal) => { hex!(@int u16, fold_u16, Little, $lit) };
+    (u16: $lit:literal) => { hex!(@int u16, fold_u16, Big, $lit) };
+    (u32 le: $lit:literal) => { hex!(@int u32, fold_u32, Little, $lit) };
+    (u32: $lit:literal) => { hex!(@int u32, fold_u32, Big, $lit) };
+    (u64 le: $lit:literal) => { hex!(@int u64, fold_u64, Little, $lit) };
+    (u64: $lit:literal) => { hex!(@int u64, fold_u64, Big, $lit) };
+    (u128 le: $lit:literal) => { hex!(@int u128, fold_u128, Little, $lit) };
+    (u128: $lit:literal) => { hex!(@int u128, fold_u128, Big, $lit) };
     ($($tt:tt)*) => {
         hex!(@string stringify!($($tt)*))
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! require_hex_float_exponent {
+    ($e:expr) => {
+        let _: $crate::internals::ExponentPresent<[(); $e as usize]>;
+    };
+}
+
+#[macro_export]
+macro_rules! hexfloat {
+    (@string f64 $arg:expr) => {{
+        const PARTS: $crate::internals::HexFloatParts =
+            $crate::internals::parse_hex_float($arg.as_bytes());
+        $crate::require_hex_float_exponent!(PARTS.has_exponent);
+        $crate::internals::hex_float_to_f64(PARTS)
+    }};
+    (@string f32 $arg:expr) => {{
+        const PARTS: $crate::internals::HexFloatParts =
+            $crate::internals::parse_hex_float($arg.as_bytes());
+        $crate::require_hex_float_exponent!(PARTS.has_exponent);
+        $crate::internals::hex_float_to_f32(PARTS)
+    }};
+    (f32: $lit:literal) => {
+        hexfloat!(@string f32 $lit)
+    };
+    ($lit:literal) => {
+        hexfloat!(@string f64 $lit)
+    };
+}
+
+#[macro_export]
+macro_rules! hex_encode {
+    (@bytes $arg:expr, $f:ident) => {{
+        const HEX_ENCODE_LEN: usize = $arg.len();
+        const HEX_ENCODE_OUT: [u8; 2 * HEX_ENCODE_LEN] =
+            $crate::internals::$f::<HEX_ENCODE_LEN, { 2 * HEX_ENCODE_LEN }>($arg);
+        $crate::internals::str_from_ascii(&HEX_ENCODE_OUT)
+    }};
+    (upper: $arg:expr) => {
+        hex_encode!(@bytes $arg, encode_upper)
+    };
+    ($arg:expr) => {
+        hex_encode!(@bytes $arg, encode)
+    };
+}
+
+#[macro_export]
+macro_rules! hex_cstr {
+    (@string $arg:expr) => {{
+        const DATA: &[u8] = $arg.as_bytes();
+        const RAW_LENGTH: usize = $arg.len() - $crate::internals::count_skipped(&DATA);
+        $crate::require_even_number_digits!(RAW_LENGTH);
+        const BYTES: [u8; RAW_LENGTH / 2] =
+            $crate::internals::convert::<{RAW_LENGTH / 2}, {$arg.len()}>(&DATA);
+        const CSTR: [u8; RAW_LENGTH / 2 + 1] =
+            $crate::internals::append_nul_terminator::<{RAW_LENGTH / 2}, {RAW_LENGTH / 2 + 1}>(&BYTES);
+        CSTR
+    }};
+    ($($tt:tt)*) => {
+        hex_cstr!(@string stringify!($($tt)*))
+    };
+}
+
 #[doc(hidden)]
 pub mod internals {
 
@@ -66,33 +203,106 @@ pub mod internals {
         type Check = ();
     }
 
-    // Count the number of occurrences of a char.
+    pub type Fits<T> = <<T as ByteCountFitsWidth>::Marker as RequireFitsWidth>::Check;
+
+    pub enum FitsWidth {}
+    pub enum ExceedsWidth {}
+
+    pub trait ByteCountFitsWidth {
+        type Marker;
+    }
+
+    impl ByteCountFitsWidth for [(); 1] {
+        type Marker = FitsWidth;
+    }
+
+    impl ByteCountFitsWidth for [(); 0] {
+        type Marker = ExceedsWidth;
+    }
+
+    pub trait RequireFitsWidth {
+        type Check;
+    }
+
+    impl RequireFitsWidth for FitsWidth {
+        type Check = ();
+    }
+
+    pub type ExponentPresent<T> =
+        <<T as HexFloatExponentLookup>::Marker as RequireHexFloatExponent>::Check;
+
+    pub enum HasExponent {}
+    pub enum MissingExponent {}
+
+    pub trait HexFloatExponentLookup {
+        type Marker;
+    }
+
+    impl HexFloatExponentLookup for [(); 1] {
+        type Marker = HasExponent;
+    }
+
+    impl HexFloatExponentLookup for [(); 0] {
+        type Marker = MissingExponent;
+    }
+
+    pub trait RequireHexFloatExponent {
+        type Check;
+    }
+
+    impl RequireHexFloatExponent for HasExponent {
+        type Check = ();
+    }
+
+    // Counts the number of bytes that do not contribute a hex digit to the
+    // decoded output: delimiters, plus the two bytes of any `0x`/`0X`
+    // prefix. Walks `data` left-to-right in a single pass, sharing
+    // `scan_past_zero` with `convert` so the two stay in lock-step on a
+    // single, un-duplicated implementation of the prefix lookahead.
+    //
+    // Behavior change from the old nested-scan implementation: a trailing,
+    // unpaired hex digit (e.g. `hex!(00 0)`) used to be counted as skipped
+    // and silently dropped, which kept the digit count even. It now counts
+    // as a digit, so the count is odd and `require_even_number_digits!`
+    // rejects it at compile time instead of quietly truncating the output.
     pub const fn count_skipped(data: &[u8]) -> usize {
-        let mut char_count: usize = 0;
-        let mut char_index: usize = 0;
-
-        while char_index < data.len() {
-            if char_index + 1 < data.len() && !is_valid_delimiter(data[char_index]) {
-                let mut next_index = char_index + 1;
-                while next_index < data.len() && is_valid_delimiter(data[next_index]) {
-                    char_count += 1;
-                    next_index += 1;
-                }
+        let mut skipped: usize = 0;
+        let mut index: usize = 0;
+        let mut at_token_start = true;
 
-                if data[char_index] == b'0'
-                    && (data[next_index] == b'x' || data[next_index] == b'X')
-                {
-                    char_count += 2;
-                }
+        while index < data.len() {
+            let byte = data[index];
 
-                char_index = next_index + 1;
-            } else {
-                char_index += 1;
-                char_count += 1;
+            if is_valid_delimiter(byte) {
+                skipped += 1;
+                index += 1;
+                continue;
             }
+
+            if at_token_start && byte == b'0' {
+                let (lookahead, is_prefix) = scan_past_zero(data, index);
+
+                if is_prefix {
+                    // `2 +` accounts for the leading `0` and the `x`/`X`
+                    // itself; the rest covers any delimiters between them.
+                    skipped += 2 + (lookahead - (index + 1));
+                    index = lookahead + 1;
+                } else {
+                    // Not a prefix: count the delimiter run `scan_past_zero`
+                    // already walked past instead of letting the outer loop
+                    // re-scan the same bytes one at a time.
+                    skipped += lookahead - (index + 1);
+                    index = lookahead;
+                    at_token_start = false;
+                }
+                continue;
+            }
+
+            at_token_start = !at_token_start;
+            index += 1;
         }
 
-        char_count
+        skipped
     }
 
     // Checks if part of set of valid delimiters.
@@ -100,57 +310,351 @@ pub mod internals {
         matches!(c, b' ' | b'"' | b'_' | b'|' | b'-' | b'\n')
     }
 
+    // From a `0` at a token boundary (`index` points at it), looks ahead
+    // past any delimiters to the next significant byte, tolerating
+    // delimiters between the `0` and what follows (so `0_x` and `0_0`
+    // both work). Returns that byte's index, plus whether it pairs with
+    // the `0` to form a `0x`/`0X` prefix.
+    //
+    // `count_skipped` and `convert` both call this instead of each
+    // re-deriving the lookahead independently, so a `0`-led token's
+    // trailing delimiter run is only ever scanned once per function: the
+    // caller jumps straight to the returned index rather than falling
+    // back to the outer loop to re-walk the same bytes one at a time.
+    const fn scan_past_zero(data: &[u8], index: usize) -> (usize, bool) {
+        let mut lookahead = index + 1;
+        while lookahead < data.len() && is_valid_delimiter(data[lookahead]) {
+            lookahead += 1;
+        }
+
+        let is_prefix = lookahead < data.len() && is_x(data[lookahead]);
+        (lookahead, is_prefix)
+    }
+
+    // Checks if `c` can introduce the `x`/`X` of a `0x` prefix.
+    const fn is_x(c: u8) -> bool {
+        c == b'x' || c == b'X'
+    }
+
+    // Checks if `c` can introduce the `p`/`P` exponent of a hex float.
+    const fn is_p(c: u8) -> bool {
+        c == b'p' || c == b'P'
+    }
+
+    // Checks if part of the set of valid hex digits.
+    const fn is_hex_digit(c: u8) -> bool {
+        c.is_ascii_hexdigit()
+    }
+
     // Converts a individual byte into its correct integer
     // counter-part.
-    #[allow(clippy::unnecessary_operation)]
     pub const fn to_ordinal(input: u8) -> u8 {
         match input {
             b'0'..=b'9' => input - b'0',
             b'A'..=b'F' => input - b'A' + 10,
             b'a'..=b'f' => input - b'a' + 10,
-            _ => {
-                #[allow(unconditional_panic)]
-                ["Invalid hex digit."][({ true } as usize)];
-                loop {} // Unreachable
-            }
+            _ => panic!("Invalid hex digit."),
         }
     }
 
-    // Converts a hex-string to its byte array representation.
+    // Converts a hex-string to its byte array representation in a single
+    // left-to-right pass: `pending_high` holds the decoded high nibble
+    // while we wait for its partner, and a `0x`/`0X` prefix is only ever
+    // recognized while we're still waiting for a high nibble, i.e. at a
+    // token boundary, never in the middle of a byte.
     pub const fn convert<const RESULT_SIZE: usize, const STRING_SIZE: usize>(
         input: &[u8],
     ) -> [u8; RESULT_SIZE] {
         let mut data = [0_u8; RESULT_SIZE];
         let mut data_index: usize = 0;
-        let mut char_index: usize = 0;
+        let mut index: usize = 0;
+        let mut pending_high: Option<u8> = None;
 
-        while data_index < STRING_SIZE && char_index + 1 < STRING_SIZE {
-            if !is_valid_delimiter(input[char_index]) {
-                let mut next_index = char_index + 1;
-                while next_index < STRING_SIZE && is_valid_delimiter(input[next_index]) {
-                    next_index += 1;
-                }
+        while index < STRING_SIZE && data_index < RESULT_SIZE {
+            let byte = input[index];
+
+            if is_valid_delimiter(byte) {
+                index += 1;
+                continue;
+            }
 
-                if !(input[char_index] == b'0'
-                    && (input[next_index] == b'x' || input[next_index] == b'X'))
-                {
-                    data[data_index] = to_ordinal(input[char_index]) * 16
-                        + to_ordinal(input[next_index]);
+            match pending_high {
+                None if byte == b'0' => {
+                    let (lookahead, is_prefix) = scan_past_zero(input, index);
+
+                    if is_prefix {
+                        index = lookahead + 1;
+                    } else {
+                        // Not a prefix: `byte` is the high nibble, and
+                        // `scan_past_zero` has already walked us past any
+                        // delimiters before its partner, so jump straight
+                        // there instead of re-scanning them one at a time.
+                        pending_high = Some(to_ordinal(byte));
+                        index = lookahead;
+                    }
+                }
+                None => {
+                    pending_high = Some(to_ordinal(byte));
+                    index += 1;
+                }
+                Some(high) => {
+                    data[data_index] = high * 16 + to_ordinal(byte);
                     data_index += 1;
+                    pending_high = None;
+                    index += 1;
                 }
-                char_index = next_index + 1;
-            } else {
-                char_index += 1;
             }
         }
         data
     }
+
+    // The parsed constituents of a `0x<int>.<frac>p<exp>` hex float
+    // literal. `mantissa` is the integer and fractional hex digits
+    // concatenated and read as one integer; `frac_digits` records how
+    // many of them came after the `.` so the value can be scaled back
+    // down by the right power of two.
+    pub struct HexFloatParts {
+        pub sign: i8,
+        pub mantissa: u128,
+        pub frac_digits: u32,
+        pub exponent: i32,
+        pub has_exponent: bool,
+    }
+
+    // Parses a hex float literal of the form `[sign]0x<int>[.<frac>]p<exp>`.
+    // The `p`/`P` binary exponent is mandatory; `has_exponent` records
+    // whether one was actually found so the caller can reject its
+    // absence at compile time, the same way `require_even_number_digits!`
+    // rejects an odd digit count for `hex!`.
+    pub const fn parse_hex_float(data: &[u8]) -> HexFloatParts {
+        let mut index: usize = 0;
+        let mut sign: i8 = 1;
+
+        if index < data.len() && data[index] == b'-' {
+            sign = -1;
+            index += 1;
+        } else if index < data.len() && data[index] == b'+' {
+            index += 1;
+        }
+
+        if !(index + 1 < data.len() && data[index] == b'0' && is_x(data[index + 1])) {
+            panic!("Hex float literal must start with `0x`.");
+        }
+        index += 2;
+
+        let mut mantissa: u128 = 0;
+        let mut frac_digits: u32 = 0;
+        let mut mantissa_digits: u32 = 0;
+
+        while index < data.len() && is_hex_digit(data[index]) {
+            mantissa = mantissa * 16 + to_ordinal(data[index]) as u128;
+            mantissa_digits += 1;
+            index += 1;
+        }
+
+        if index < data.len() && data[index] == b'.' {
+            index += 1;
+            while index < data.len() && is_hex_digit(data[index]) {
+                mantissa = mantissa * 16 + to_ordinal(data[index]) as u128;
+                frac_digits += 1;
+                mantissa_digits += 1;
+                index += 1;
+            }
+        }
+
+        if mantissa_digits == 0 {
+            panic!("Hex float literal must have at least one mantissa digit.");
+        }
+
+        let has_exponent = index < data.len() && is_p(data[index]);
+        let mut exponent: i32 = 0;
+
+        if has_exponent {
+            index += 1;
+            let mut exponent_sign: i32 = 1;
+
+            if index < data.len() && data[index] == b'-' {
+                exponent_sign = -1;
+                index += 1;
+            } else if index < data.len() && data[index] == b'+' {
+                index += 1;
+            }
+
+            let mut exponent_digits: u32 = 0;
+            while index < data.len() && data[index].is_ascii_digit() {
+                exponent = exponent * 10 + (data[index] - b'0') as i32;
+                exponent_digits += 1;
+                index += 1;
+            }
+
+            if exponent_digits == 0 {
+                panic!("Hex float exponent must have at least one digit.");
+            }
+
+            exponent *= exponent_sign;
+        }
+
+        if index != data.len() {
+            panic!("Hex float literal has trailing characters after the exponent.");
+        }
+
+        HexFloatParts {
+            sign,
+            mantissa,
+            frac_digits,
+            exponent,
+            has_exponent,
+        }
+    }
+
+    // Computes `2^exponent` in O(1) by building the IEEE 754 bit pattern
+    // directly, since `f64::powi` is not available in a `const fn` and a
+    // repeated doubling/halving loop would make compile time proportional
+    // to the exponent's magnitude, which is attacker/typo controlled
+    // (`hexfloat!("0x1p2147483647")` would otherwise cost real wall-clock
+    // compile time for no reason).
+    const fn pow2(exponent: i32) -> f64 {
+        if exponent > 1023 {
+            return f64::INFINITY;
+        }
+        if exponent < -1074 {
+            return 0.0;
+        }
+
+        if exponent >= -1022 {
+            // Normal range: `2^exponent` is exactly `1.0 * 2^exponent`, an
+            // all-zero mantissa with the biased exponent set directly.
+            f64::from_bits(((exponent + 1023) as u64) << 52)
+        } else {
+            // Subnormal range (-1074..-1022): there's no biased exponent
+            // left to set, so `2^exponent` is encoded as a single mantissa
+            // bit at position `exponent + 1074`.
+            f64::from_bits(1_u64 << (exponent + 1074))
+        }
+    }
+
+    // Evaluates parsed hex-float parts as
+    // `sign * mantissa * 2^(exponent - 4 * frac_digits)`.
+    pub const fn hex_float_to_f64(parts: HexFloatParts) -> f64 {
+        let magnitude =
+            parts.mantissa as f64 * pow2(parts.exponent - 4 * parts.frac_digits as i32);
+
+        if parts.sign < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    pub const fn hex_float_to_f32(parts: HexFloatParts) -> f32 {
+        hex_float_to_f64(parts) as f32
+    }
+
+    const LOWER_HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    const UPPER_HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+    // Encodes `bytes` as lowercase ASCII hex, the inverse of `convert`.
+    // `OUT` must equal `2 * N`; callers go through `hex_encode!`, which
+    // computes it for them the same way `hex!` computes `convert`'s size.
+    pub const fn encode<const N: usize, const OUT: usize>(bytes: &[u8; N]) -> [u8; OUT] {
+        encode_with(bytes, LOWER_HEX_DIGITS)
+    }
+
+    // Encodes `bytes` as uppercase ASCII hex, the inverse of `convert`.
+    pub const fn encode_upper<const N: usize, const OUT: usize>(bytes: &[u8; N]) -> [u8; OUT] {
+        encode_with(bytes, UPPER_HEX_DIGITS)
+    }
+
+    const fn encode_with<const N: usize, const OUT: usize>(
+        bytes: &[u8; N],
+        digits: &[u8; 16],
+    ) -> [u8; OUT] {
+        let mut out = [0_u8; OUT];
+        let mut index = 0;
+
+        while index < N {
+            let byte = bytes[index];
+            out[index * 2] = digits[(byte >> 4) as usize];
+            out[index * 2 + 1] = digits[(byte & 0x0F) as usize];
+            index += 1;
+        }
+
+        out
+    }
+
+    // Reinterprets the ASCII hex digits produced by `encode`/`encode_upper`
+    // as a `&str`. Always succeeds since both lookup tables only emit
+    // ASCII bytes.
+    pub const fn str_from_ascii(bytes: &[u8]) -> &str {
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => panic!("hex_encode! produced non-UTF-8 output"),
+        }
+    }
+
+    // Byte order for the integer-output arms of `hex!`.
+    pub enum Endian {
+        Big,
+        Little,
+    }
+
+    // Generates a `$name` function that packs a byte slice into a `$ty`,
+    // most significant byte first or last depending on `Endian`.
+    // `require_fits_in_width!` already guarantees `bytes.len()` does
+    // not exceed `size_of::<$ty>()`.
+    macro_rules! fold_uint {
+        ($name:ident, $ty:ty) => {
+            pub const fn $name(bytes: &[u8], endian: Endian) -> $ty {
+                let mut value: $ty = 0;
+                let len = bytes.len();
+                let mut index = 0;
+
+                while index < len {
+                    let byte = match endian {
+                        Endian::Big => bytes[index],
+                        Endian::Little => bytes[len - 1 - index],
+                    };
+                    value = (value << 8) | byte as $ty;
+                    index += 1;
+                }
+
+                value
+            }
+        };
+    }
+
+    fold_uint!(fold_u16, u16);
+    fold_uint!(fold_u32, u32);
+    fold_uint!(fold_u64, u64);
+    fold_uint!(fold_u128, u128);
+
+    // Appends a trailing NUL to `bytes`, the layout `CStr::from_bytes_with_nul`
+    // expects. Rejects an embedded `00` byte at compile time, since that
+    // would terminate the C string early.
+    pub const fn append_nul_terminator<const N: usize, const OUT: usize>(
+        bytes: &[u8; N],
+    ) -> [u8; OUT] {
+        let mut check_index = 0;
+        while check_index < N {
+            if bytes[check_index] == 0 {
+                panic!("Embedded NUL byte in hex_cstr! payload.");
+            }
+            check_index += 1;
+        }
+
+        let mut out = [0_u8; OUT];
+        let mut index = 0;
+        while index < N {
+            out[index] = bytes[index];
+            index += 1;
+        }
+
+        out
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::hex;
-
     #[test]
     fn test_leading_zeros() {
         assert_eq!(hex!("01020304"), [1, 2, 3, 4]);
@@ -260,4 +764,82 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_hexfloat_f64() {
+        assert_eq!(hexfloat!("0x1.8p3"), 12.0_f64);
+        assert_eq!(hexfloat!("0x1p0"), 1.0_f64);
+        assert_eq!(hexfloat!("-0x1.8p3"), -12.0_f64);
+        assert_eq!(hexfloat!("0x1.fp4"), 31.0_f64);
+    }
+
+    #[test]
+    fn test_hexfloat_f32() {
+        assert_eq!(hexfloat!(f32: "0x1p0"), 1.0_f32);
+        assert_eq!(hexfloat!(f32: "0x1.8p3"), 12.0_f32);
+    }
+
+    #[test]
+    fn test_hexfloat_negative_exponent() {
+        assert_eq!(hexfloat!("0x1p-1"), 0.5_f64);
+    }
+
+    #[test]
+    fn test_hexfloat_extreme_exponent() {
+        // `pow2` builds the `f64` bit pattern directly rather than looping,
+        // so these stay O(1) at compile time regardless of magnitude.
+        assert_eq!(hexfloat!("0x1p1023"), f64::from_bits(2046_u64 << 52));
+        assert_eq!(hexfloat!("0x1p1024"), f64::INFINITY);
+        assert_eq!(hexfloat!("0x1p-1074"), f64::from_bits(1));
+        assert_eq!(hexfloat!("0x1p-1075"), 0.0_f64);
+        assert_eq!(hexfloat!("0x1p2147483647"), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_hex_encode_lower() {
+        assert_eq!(hex_encode!(&[0xDE, 0xAD, 0xBE, 0xEF]), "deadbeef");
+    }
+
+    #[test]
+    fn test_hex_encode_upper() {
+        assert_eq!(hex_encode!(upper: &[0xDE, 0xAD, 0xBE, 0xEF]), "DEADBEEF");
+    }
+
+    #[test]
+    fn test_hex_encode_round_trip() {
+        const BYTES: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
+        const ENCODED: &str = hex_encode!(&BYTES);
+        assert_eq!(hex!(@string ENCODED), BYTES);
+    }
+
+    #[test]
+    fn test_hex_uint_big_endian() {
+        assert_eq!(hex!(u16: "00FF"), 0x00FF_u16);
+        assert_eq!(hex!(u32: "DEADBEEF"), 0xDEADBEEF_u32);
+        assert_eq!(hex!(u64: "0102030405060708"), 0x0102030405060708_u64);
+        assert_eq!(
+            hex!(u128: "000102030405060708090A0B0C0D0E0F"),
+            0x000102030405060708090A0B0C0D0E0F_u128
+        );
+    }
+
+    #[test]
+    fn test_hex_uint_little_endian() {
+        assert_eq!(hex!(u32 le: "01000000"), 1_u32);
+        assert_eq!(hex!(u16 le: "FF00"), 0x00FF_u16);
+    }
+
+    #[test]
+    fn test_hex_uint_narrower_than_width() {
+        assert_eq!(hex!(u32: "00FF"), 0x00FF_u32);
+    }
+
+    #[test]
+    fn test_hex_cstr() {
+        assert_eq!(
+            hex_cstr!("68656C6C6F"),
+            [0x68, 0x65, 0x6C, 0x6C, 0x6F, 0]
+        );
+        assert_eq!(hex_cstr!(DE AD BE EF), [0xDE, 0xAD, 0xBE, 0xEF, 0]);
+    }
 }